@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use slicing_perf::{splice, splice_parallel, splice_stepped};
+use slicing_perf::{splice, splice_blocked, splice_into, splice_parallel, splice_stepped};
 
 fn simple_benchmark(c: &mut Criterion) {
     c.bench_function("splice(4, [0..=6])", |b| {
@@ -24,12 +24,29 @@ fn throughput_benchmark(c: &mut Criterion) {
         group.bench_with_input(BenchmarkId::new("splice", size), &input, |b, i| {
             b.iter(|| splice(black_box(5), i))
         });
+        // Apples-to-apples against allocating `splice`: reuse the same output
+        // buffers across iterations so only the deinterleave work is measured.
+        group.bench_with_input(BenchmarkId::new("splice_into", size), &input, |b, i| {
+            let mut out = vec![Vec::new(); 5];
+            b.iter(|| splice_into(black_box(5), i, &mut out))
+        });
         group.bench_with_input(BenchmarkId::new("splice_stepped", size), &input, |b, i| {
             b.iter(|| splice_stepped(black_box(5), i))
         });
         group.bench_with_input(BenchmarkId::new("splice_parallel", size), &input, |b, i| {
             b.iter(|| splice_parallel(black_box(5), i))
         });
+        group.bench_with_input(BenchmarkId::new("splice_blocked", size), &input, |b, i| {
+            b.iter(|| splice_blocked(black_box(5), i))
+        });
+        // SIMD fast-path channel counts, to quantify the speedup over the
+        // generic `i % channels` path.
+        group.bench_with_input(BenchmarkId::new("splice_c2", size), &input, |b, i| {
+            b.iter(|| splice(black_box(2), i))
+        });
+        group.bench_with_input(BenchmarkId::new("splice_c4", size), &input, |b, i| {
+            b.iter(|| splice(black_box(4), i))
+        });
     }
 }
 
@@ -1,8 +1,38 @@
 use rayon::prelude::*;
+use std::io::{self, Read, Write};
+
+/// Number of bytes pulled from the source per `read` in [`splice_stream`].
+const SPLICE_STREAM_CHUNK: usize = 8 * 1024;
+
+/// Tile edge (in elements) for [`splice_blocked`]'s blocked transpose.
+const SPLICE_BLOCK_TILE: usize = 64;
 
 #[inline]
-pub fn splice(channels: usize, data: &[u8]) -> Vec<Vec<u8>> {
+pub fn splice<T: Copy>(channels: usize, data: &[T]) -> Vec<Vec<T>> {
     assert!(channels > 0);
+    // Fast paths for the common single-byte de-interleave counts (AoS->SoA,
+    // pixel splitting). Deinterleaving only shuffles bytes between positions, so
+    // any 1-byte `Copy` element reuses the `u8` SIMD kernels unchanged; gating on
+    // the element width keeps the generic `T: Copy` promise from chunk0-4 and
+    // avoids a `'static` bound.
+    if std::mem::size_of::<T>() == 1 && (channels == 2 || channels == 4) {
+        // SAFETY: `T` is exactly one byte wide (hence align 1), so the input
+        // slice and the `Vec<Vec<u8>>` result are layout-identical to their `T`
+        // forms.
+        let bytes: &[u8] =
+            unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, data.len()) };
+        let spliced = splice_u8_simd(channels, bytes);
+        let out = unsafe { std::mem::transmute_copy::<Vec<Vec<u8>>, Vec<Vec<T>>>(&spliced) };
+        std::mem::forget(spliced);
+        return out;
+    }
+    splice_scalar(channels, data)
+}
+
+/// The generic per-element transpose used by [`splice`] for every element type
+/// and as the fallback for channel counts / targets without a SIMD kernel.
+#[inline]
+fn splice_scalar<T: Copy>(channels: usize, data: &[T]) -> Vec<Vec<T>> {
     let each_len = data.len() / channels + if data.len() % channels == 0 { 0 } else { 1 };
     let mut out = vec![Vec::with_capacity(each_len); channels];
     for (i, d) in data.iter().copied().enumerate() {
@@ -11,8 +41,212 @@ pub fn splice(channels: usize, data: &[u8]) -> Vec<Vec<u8>> {
     out
 }
 
+/// `u8`-specialized deinterleave that uses `std::arch` SIMD shuffles for 2 and 4
+/// channels when the CPU supports them, selected via runtime feature detection,
+/// and otherwise defers to the portable scalar loop so the crate builds and runs
+/// everywhere.
+fn splice_u8_simd(channels: usize, data: &[u8]) -> Vec<Vec<u8>> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if is_x86_feature_detected!("ssse3") {
+            // SAFETY: guarded by the `ssse3` runtime check just above.
+            match channels {
+                2 => return unsafe { splice2_ssse3(data) },
+                4 => return unsafe { splice4_ssse3(data) },
+                _ => {}
+            }
+        }
+    }
+    splice_scalar(channels, data)
+}
+
+/// SSSE3 kernel for 2 channels: splits 32 input bytes (16 pairs) per iteration
+/// into 16 even-indexed and 16 odd-indexed bytes, leaving the ragged tail to the
+/// scalar loop.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "ssse3")]
+unsafe fn splice2_ssse3(data: &[u8]) -> Vec<Vec<u8>> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    let len = data.len();
+    let mut ch0 = vec![0u8; len - len / 2]; // evens: ceil(len / 2)
+    let mut ch1 = vec![0u8; len / 2]; // odds
+    // Gather even bytes into the low 8 lanes and odd bytes into the high 8.
+    let mask = _mm_setr_epi8(0, 2, 4, 6, 8, 10, 12, 14, 1, 3, 5, 7, 9, 11, 13, 15);
+    let blocks = len / 32;
+    for b in 0..blocks {
+        let base = b * 32;
+        let v0 = _mm_loadu_si128(data.as_ptr().add(base) as *const __m128i);
+        let v1 = _mm_loadu_si128(data.as_ptr().add(base + 16) as *const __m128i);
+        let s0 = _mm_shuffle_epi8(v0, mask);
+        let s1 = _mm_shuffle_epi8(v1, mask);
+        let evens = _mm_unpacklo_epi64(s0, s1);
+        let odds = _mm_unpackhi_epi64(s0, s1);
+        _mm_storeu_si128(ch0.as_mut_ptr().add(b * 16) as *mut __m128i, evens);
+        _mm_storeu_si128(ch1.as_mut_ptr().add(b * 16) as *mut __m128i, odds);
+    }
+    for i in (blocks * 32)..len {
+        if i % 2 == 0 {
+            ch0[i / 2] = data[i];
+        } else {
+            ch1[i / 2] = data[i];
+        }
+    }
+    vec![ch0, ch1]
+}
+
+/// SSSE3 kernel for 4 channels: splits 64 input bytes (16 quads) per iteration
+/// into four 16-byte channel runs, leaving the ragged tail to the scalar loop.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "ssse3")]
+unsafe fn splice4_ssse3(data: &[u8]) -> Vec<Vec<u8>> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    let len = data.len();
+    let rem = len % 4;
+    let base_len = len / 4;
+    let mut out: Vec<Vec<u8>> = (0..4)
+        .map(|c| vec![0u8; base_len + if c < rem { 1 } else { 0 }])
+        .collect();
+    // Within each 16-byte register, group the four interleaved channels into
+    // contiguous 4-byte (u32) lanes: lane0 = channel 0's bytes, etc.
+    let mask = _mm_setr_epi8(0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15);
+    let blocks = len / 64;
+    for b in 0..blocks {
+        let base = b * 64;
+        let t0 = _mm_shuffle_epi8(
+            _mm_loadu_si128(data.as_ptr().add(base) as *const __m128i),
+            mask,
+        );
+        let t1 = _mm_shuffle_epi8(
+            _mm_loadu_si128(data.as_ptr().add(base + 16) as *const __m128i),
+            mask,
+        );
+        let t2 = _mm_shuffle_epi8(
+            _mm_loadu_si128(data.as_ptr().add(base + 32) as *const __m128i),
+            mask,
+        );
+        let t3 = _mm_shuffle_epi8(
+            _mm_loadu_si128(data.as_ptr().add(base + 48) as *const __m128i),
+            mask,
+        );
+        // Each t* holds u32 lanes [A, B, C, D]; transpose the 4x4 u32 matrix so
+        // each output register holds one channel's four bytes from all blocks.
+        let lo01 = _mm_unpacklo_epi32(t0, t1); // [A0,A1,B0,B1]
+        let hi01 = _mm_unpackhi_epi32(t0, t1); // [C0,C1,D0,D1]
+        let lo23 = _mm_unpacklo_epi32(t2, t3); // [A2,A3,B2,B3]
+        let hi23 = _mm_unpackhi_epi32(t2, t3); // [C2,C3,D2,D3]
+        let a = _mm_unpacklo_epi64(lo01, lo23);
+        let bb = _mm_unpackhi_epi64(lo01, lo23);
+        let cc = _mm_unpacklo_epi64(hi01, hi23);
+        let dd = _mm_unpackhi_epi64(hi01, hi23);
+        let off = b * 16;
+        _mm_storeu_si128(out[0].as_mut_ptr().add(off) as *mut __m128i, a);
+        _mm_storeu_si128(out[1].as_mut_ptr().add(off) as *mut __m128i, bb);
+        _mm_storeu_si128(out[2].as_mut_ptr().add(off) as *mut __m128i, cc);
+        _mm_storeu_si128(out[3].as_mut_ptr().add(off) as *mut __m128i, dd);
+    }
+    for i in (blocks * 64)..len {
+        out[i % 4][i / 4] = data[i];
+    }
+    out
+}
+
+/// Like [`splice`], but deinterleaves into caller-owned buffers instead of
+/// allocating a fresh `Vec<Vec<u8>>` on every call. Each buffer in `out` is
+/// cleared and then refilled, so a hot loop demuxing successive packets into
+/// the same channel buffers never touches the allocator once the buffers are
+/// warm. `out` must have exactly `channels` entries.
 #[inline]
-pub fn splice_stepped(channels: usize, data: &[u8]) -> Vec<Vec<u8>> {
+pub fn splice_into(channels: usize, data: &[u8], out: &mut [Vec<u8>]) {
+    assert!(channels > 0);
+    assert_eq!(out.len(), channels);
+    for buf in out.iter_mut() {
+        buf.clear();
+    }
+    for (i, d) in data.iter().copied().enumerate() {
+        out[i % channels].push(d);
+    }
+}
+
+/// Slice-backed variant of [`splice_into`] that writes into pre-sized borrows
+/// rather than growable `Vec`s, for callers that manage their own storage.
+/// Channel `c` receives the elements `data[c], data[c + channels], ...`; its
+/// slice must be long enough to hold `data.len() / channels` (plus one for the
+/// first `data.len() % channels` channels). `out` must have exactly `channels`
+/// entries.
+#[inline]
+pub fn splice_into_slices(channels: usize, data: &[u8], out: &mut [&mut [u8]]) {
+    assert!(channels > 0);
+    assert_eq!(out.len(), channels);
+    for (i, d) in data.iter().copied().enumerate() {
+        out[i % channels][i / channels] = d;
+    }
+}
+
+/// Deinterleave a stream of bytes from `src` into one `Write` sink per channel,
+/// so that element `k` of the overall stream always lands in channel
+/// `k % channels` regardless of where the chunk boundaries fall. The source is
+/// drained in fixed-size chunks, which makes this usable for pipes, sockets and
+/// files that do not fit in memory. Returns the total number of bytes read so a
+/// caller can resume. `sinks` must have exactly `channels` entries.
+pub fn splice_stream<R: Read, W: Write>(
+    channels: usize,
+    src: R,
+    sinks: &mut [W],
+) -> io::Result<usize> {
+    splice_stream_chunked(channels, src, sinks, SPLICE_STREAM_CHUNK)
+}
+
+/// The engine behind [`splice_stream`], with an explicit chunk size so the
+/// phase-continuity invariant can be exercised across chunk boundaries.
+fn splice_stream_chunked<R: Read, W: Write>(
+    channels: usize,
+    mut src: R,
+    sinks: &mut [W],
+    chunk: usize,
+) -> io::Result<usize> {
+    assert!(channels > 0);
+    assert!(chunk > 0);
+    assert_eq!(sinks.len(), channels);
+    let mut buf = vec![0u8; chunk];
+    // Per-channel scratch filled for each chunk, so every sink takes a single
+    // `write_all` per chunk rather than one syscall per byte.
+    let mut routed: Vec<Vec<u8>> = vec![Vec::with_capacity(chunk / channels + 1); channels];
+    // `total` is carried across chunk boundaries: it is the global index of
+    // `buf[0]`, so routing by `(total + i) % channels` stays in phase no matter
+    // how reads are split.
+    let mut total = 0usize;
+    loop {
+        let n = src.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        for buf in routed.iter_mut() {
+            buf.clear();
+        }
+        for (i, &b) in buf[..n].iter().enumerate() {
+            routed[(total + i) % channels].push(b);
+        }
+        for (sink, bytes) in sinks.iter_mut().zip(routed.iter()) {
+            sink.write_all(bytes)?;
+        }
+        total += n;
+    }
+    for sink in sinks.iter_mut() {
+        sink.flush()?;
+    }
+    Ok(total)
+}
+
+#[inline]
+pub fn splice_stepped<T: Copy>(channels: usize, data: &[T]) -> Vec<Vec<T>> {
     assert!(channels > 0);
     (0..channels)
         .map(|offset| {
@@ -25,8 +259,75 @@ pub fn splice_stepped(channels: usize, data: &[u8]) -> Vec<Vec<u8>> {
         .collect()
 }
 
+/// Cache-blocked deinterleaver for large inputs. Deinterleaving is a transpose
+/// of the `each_len × channels` row-major matrix formed by `data` into
+/// `channels` output rows; doing it a tile at a time keeps each output's write
+/// region resident in cache while the input is read in long sequential runs,
+/// instead of the stride-`channels` reads in [`splice_stepped`] or the per-byte
+/// modulo in [`splice`]. Outputs are preallocated and filled by index. The last
+/// row/column tiles are clamped to the real bounds so the ragged tail is
+/// handled like in [`splice`].
 #[inline]
-pub fn splice_parallel(channels: usize, data: &[u8]) -> Vec<Vec<u8>> {
+pub fn splice_blocked(channels: usize, data: &[u8]) -> Vec<Vec<u8>> {
+    assert!(channels > 0);
+    let len = data.len();
+    let rem = len % channels;
+    let each_len = len / channels + if rem == 0 { 0 } else { 1 };
+    let mut out: Vec<Vec<u8>> = (0..channels)
+        .map(|c| vec![0u8; len / channels + if c < rem { 1 } else { 0 }])
+        .collect();
+    // `col` stays innermost so the input is read in long sequential runs
+    // (`data[base..base + ...]`); iterating the tile ranges rather than the
+    // output slices is what preserves that access pattern, so the range-loop
+    // lint is deliberately allowed here.
+    #[allow(clippy::needless_range_loop)]
+    for ti in (0..each_len).step_by(SPLICE_BLOCK_TILE) {
+        let row_end = (ti + SPLICE_BLOCK_TILE).min(each_len);
+        for tj in (0..channels).step_by(SPLICE_BLOCK_TILE) {
+            let col_end = (tj + SPLICE_BLOCK_TILE).min(channels);
+            for row in ti..row_end {
+                let base = row * channels;
+                for col in tj..col_end {
+                    let idx = base + col;
+                    if idx < len {
+                        out[col][row] = data[idx];
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Recombine per-channel buffers back into the original interleaved stream, the
+/// inverse of [`splice`]: `interleave(&splice(n, data)) == data`. The tail is
+/// ragged when `data.len() % n != 0` — earlier channels hold one more element —
+/// so each position pulls only from the channels that still have an element,
+/// rather than assuming every channel is the same length.
+#[inline]
+pub fn interleave(channels: &[Vec<u8>]) -> Vec<u8> {
+    let mut out = Vec::new();
+    interleave_into(channels, &mut out);
+    out
+}
+
+/// Buffer-reuse form of [`interleave`]; `out` is cleared and refilled.
+#[inline]
+pub fn interleave_into(channels: &[Vec<u8>], out: &mut Vec<u8>) {
+    out.clear();
+    out.reserve(channels.iter().map(Vec::len).sum());
+    let max_len = channels.iter().map(Vec::len).max().unwrap_or(0);
+    for pos in 0..max_len {
+        for channel in channels {
+            if let Some(&d) = channel.get(pos) {
+                out.push(d);
+            }
+        }
+    }
+}
+
+#[inline]
+pub fn splice_parallel<T: Copy + Send + Sync>(channels: usize, data: &[T]) -> Vec<Vec<T>> {
     assert!(channels > 0);
     (0..channels)
         .map(|offset| {
@@ -52,7 +353,11 @@ fn check_splicer(f: impl Fn(usize, &[u8]) -> Vec<Vec<u8>>) {
         vec![vec![0, 6], vec![1, 7], vec![2], vec![3], vec![4], vec![5]],
     ];
     for (channels, expected) in expected.into_iter().enumerate().skip(1) {
-        assert_eq!(f(channels, &input), expected, "f({channels}, ...)");
+        let spliced = f(channels, &input);
+        assert_eq!(spliced, expected, "f({channels}, ...)");
+        // Round-trip: recombining the channels must reproduce the input,
+        // including the ragged tail when channels do not divide the length.
+        assert_eq!(interleave(&spliced), input, "interleave after f({channels}, ...)");
     }
 }
 
@@ -61,12 +366,109 @@ fn test_splice() {
     check_splicer(splice);
 }
 
+#[test]
+fn test_splice_into() {
+    check_splicer(|channels, data| {
+        let mut out = vec![Vec::new(); channels];
+        splice_into(channels, data, &mut out);
+        out
+    });
+}
+
+#[test]
+fn test_splice_into_reuses_buffers() {
+    let mut out = vec![Vec::new(); 2];
+    splice_into(2, &[0, 1, 2, 3], &mut out);
+    assert_eq!(out, [vec![0, 2], vec![1, 3]]);
+    // A second call into the same buffers must not append to the first result.
+    splice_into(2, &[4, 5], &mut out);
+    assert_eq!(out, [vec![4], vec![5]]);
+}
+
+#[test]
+fn test_splice_into_slices() {
+    check_splicer(|channels, data| {
+        let each_len = data.len() / channels + if data.len() % channels == 0 { 0 } else { 1 };
+        let mut storage = vec![vec![0u8; each_len]; channels];
+        let mut borrows: Vec<&mut [u8]> = storage.iter_mut().map(|v| v.as_mut_slice()).collect();
+        splice_into_slices(channels, data, &mut borrows);
+        // Trim the ragged tail so the result matches the growable variants.
+        storage
+            .into_iter()
+            .enumerate()
+            .map(|(c, mut v)| {
+                let len = data.len() / channels + if c < data.len() % channels { 1 } else { 0 };
+                v.truncate(len);
+                v
+            })
+            .collect()
+    });
+}
+
+#[test]
+fn test_splice_stream() {
+    check_splicer(|channels, data| {
+        let mut sinks: Vec<Vec<u8>> = vec![Vec::new(); channels];
+        let total = splice_stream(channels, data, &mut sinks).unwrap();
+        assert_eq!(total, data.len());
+        sinks
+    });
+}
+
+#[test]
+fn test_splice_stream_preserves_phase_across_chunks() {
+    let input: Vec<u8> = (0..20).collect();
+    // A chunk of 3 is not a multiple of the channel count (5), so most chunk
+    // boundaries (3, 6, 9, 12, 18) fall mid-phase; the result must still match a
+    // whole-slice splice.
+    let mut sinks: Vec<Vec<u8>> = vec![Vec::new(); 5];
+    let total = splice_stream_chunked(5, input.as_slice(), &mut sinks, 3).unwrap();
+    assert_eq!(total, input.len());
+    assert_eq!(sinks, splice(5, &input));
+}
+
 #[test]
 fn test_splice_stepped() {
     check_splicer(splice_stepped);
 }
 
+#[test]
+fn test_splice_blocked() {
+    check_splicer(splice_blocked);
+}
+
+#[test]
+fn test_splice_blocked_large_matches_splice() {
+    // Exercise several tiles in both dimensions with a ragged tail.
+    let input: Vec<u8> = (0..200u32).map(|i| i as u8).collect();
+    for channels in [1usize, 2, 3, 5, 70] {
+        assert_eq!(splice_blocked(channels, &input), splice(channels, &input));
+    }
+}
+
 #[test]
 fn test_splice_parallel() {
     check_splicer(splice_parallel);
 }
+
+#[test]
+fn test_splice_simd_matches_scalar() {
+    // Cover whole SIMD blocks plus a ragged tail for both fast-path counts.
+    let input: Vec<u8> = (0..333u32).map(|i| (i * 7) as u8).collect();
+    for channels in [2usize, 4] {
+        assert_eq!(splice(channels, &input), splice_scalar(channels, &input));
+    }
+}
+
+#[test]
+fn test_splice_generic_types() {
+    // i16 audio frames: deinterleave a stereo pair.
+    let frames: [i16; 6] = [-1, 100, -2, 200, -3, 300];
+    assert_eq!(splice(2, &frames), [vec![-1, -2, -3], vec![100, 200, 300]]);
+    assert_eq!(splice_stepped(2, &frames), splice(2, &frames));
+    assert_eq!(splice_parallel(2, &frames), splice(2, &frames));
+
+    // RGBA pixels as [u8; 4]: two pixels into two "planes".
+    let pixels: [[u8; 4]; 2] = [[1, 2, 3, 4], [5, 6, 7, 8]];
+    assert_eq!(splice(2, &pixels), [vec![[1, 2, 3, 4]], vec![[5, 6, 7, 8]]]);
+}